@@ -8,6 +8,7 @@ from starlette.middleware import Middleware as StarletteMiddleware
 from starlette.middleware.cors import CORSMiddleware
 from starlette.middleware.gzip import GZipMiddleware
 from starlette.middleware.trustedhost import TrustedHostMiddleware
+from starlette.routing import get_name
 from starlette.staticfiles import StaticFiles
 from starlette.types import ASGIApp, Receive, Scope, Send
 from typing_extensions import Type
@@ -61,12 +62,14 @@ class Starlite(Router):
         "cors_config",
         "debug",
         "gzip_config",
+        "method_not_allowed_as_404",
         "openapi_schema",
         "plain_routes",
         "plugins",
         "route_map",
         "state",
         "static_paths",
+        "strip_root_path",
         "template_engine",
     )
 
@@ -84,6 +87,7 @@ class Starlite(Router):
         exception_handlers: Optional[Dict[Union[int, Type[Exception]], ExceptionHandler]] = None,
         guards: Optional[List[Guard]] = None,
         gzip_config: Optional[GZIPConfig] = None,
+        method_not_allowed_as_404: bool = False,
         middleware: Optional[List[Middleware]] = None,
         on_shutdown: Optional[List[LifeCycleHandler]] = None,
         on_startup: Optional[List[LifeCycleHandler]] = None,
@@ -93,6 +97,7 @@ class Starlite(Router):
         response_headers: Optional[Dict[str, ResponseHeader]] = None,
         route_handlers: List[ControllerRouterHandler],
         static_files_config: Optional[Union[StaticFilesConfig, List[StaticFilesConfig]]] = None,
+        strip_root_path: bool = True,
         template_config: Optional[TemplateConfig] = None,
     ):
         self.allowed_hosts = allowed_hosts
@@ -100,12 +105,14 @@ class Starlite(Router):
         self.cors_config = cors_config
         self.debug = debug
         self.gzip_config = gzip_config
+        self.method_not_allowed_as_404 = method_not_allowed_as_404
         self.plain_routes: Set[str] = set()
         self.plugins = plugins or []
         self.route_map: Dict[str, Any] = {}
         self.routes: List[BaseRoute] = []
         self.state = State()
         self.static_paths = set()
+        self.strip_root_path = strip_root_path
 
         super().__init__(
             dependencies=dependencies,
@@ -179,18 +186,28 @@ class Starlite(Router):
         Create a map of the app's routes. This map is used in the asgi router to route requests.
 
         """
-        seen_param_paths = set()
+        seen_param_paths: Dict[str, str] = {}
+        asgi_paths = {route.path for route in self.routes if isinstance(route, ASGIRoute)}
+        # static paths are always mounts, ASGI routes only if no other route is registered below their path
+        mount_paths = self.static_paths | {
+            asgi_path
+            for asgi_path in asgi_paths
+            if not any(
+                route.path != asgi_path and route.path.startswith(asgi_path.rstrip("/") + "/") for route in self.routes
+            )
+        }
         if "_components" not in self.route_map:
             self.route_map["_components"] = set()
         for route in self.routes:
             path = route.path
-            if route.path_parameters or path in self.static_paths:
+            if route.path_parameters or path in self.static_paths or path in asgi_paths:
+                self.plain_routes.discard(path)
                 for param_definition in route.path_parameters:
                     path = path.replace(param_definition["full"], "")
                 path = path.replace("{}", "*")
-                if path in seen_param_paths:
+                if seen_param_paths.get(path, route.path) != route.path:
                     raise ImproperlyConfiguredException("Should not use routes with conflicting path parameters")
-                seen_param_paths.add(path)
+                seen_param_paths[path] = route.path
                 cur = self.route_map
                 components = ["/", *[component for component in path.split("/") if component]]
                 for component in components:
@@ -210,6 +227,7 @@ class Starlite(Router):
                 cur["_asgi_handlers"] = {}
             if "_is_asgi" not in cur:
                 cur["_is_asgi"] = False
+            cur["_is_mount"] = route.path in mount_paths
             if path in self.static_paths:
                 cur["static_path"] = path
                 cur["_is_asgi"] = True
@@ -223,29 +241,47 @@ class Starlite(Router):
             elif isinstance(route, ASGIRoute):
                 asgi_handlers["asgi"] = self.build_route_middleware_stack(route, route.route_handler)
                 cur["_is_asgi"] = True
+            if cur.get("static_path") and any(key != "asgi" for key in asgi_handlers):
+                raise ImproperlyConfiguredException(
+                    f"Cannot register route handlers on {path!r}, it is used to serve static files"
+                )
 
     def build_route_middleware_stack(
         self,
         route: Union[HTTPRoute, WebSocketRoute, ASGIRoute],
         route_handler: Union[HTTPRouteHandler, WebsocketRouteHandler, ASGIRouteHandler],
     ) -> ASGIApp:
-        """Constructs a middleware stack that serves as the point of entry for each route"""
+        """
+        Constructs a middleware stack that serves as the point of entry for each route
 
-        # we wrap the route.handle method in the ExceptionHandlerMiddleware
-        asgi_handler = self.wrap_in_exception_handler(
-            app=route.handle, exception_handlers=route_handler.resolve_exception_handlers()
-        )
+        Raises ImproperlyConfiguredException naming the route and handler if the stack cannot be built
+        """
+        try:
+            # we wrap the route.handle method in the ExceptionHandlerMiddleware
+            asgi_handler = self.wrap_in_exception_handler(
+                app=route.handle, exception_handlers=route_handler.resolve_exception_handlers()
+            )
 
-        for middleware in route_handler.resolve_middleware():
-            if isinstance(middleware, StarletteMiddleware):
-                asgi_handler = middleware.cls(app=asgi_handler, **middleware.options)
-            else:
-                asgi_handler = middleware(app=asgi_handler)
+            for middleware in route_handler.resolve_middleware():
+                if isinstance(middleware, StarletteMiddleware):
+                    asgi_handler = middleware.cls(app=asgi_handler, **middleware.options)
+                else:
+                    asgi_handler = middleware(app=asgi_handler)
 
-        # we wrap the entire stack again in ExceptionHandlerMiddleware
-        return self.wrap_in_exception_handler(
-            app=asgi_handler, exception_handlers=route_handler.resolve_exception_handlers()
-        )
+            # we wrap the entire stack again in ExceptionHandlerMiddleware
+            return self.wrap_in_exception_handler(
+                app=asgi_handler, exception_handlers=route_handler.resolve_exception_handlers()
+            )
+        except Exception as e:  # pylint: disable=broad-except
+            route_kind = (
+                ", ".join(route_handler.http_methods)
+                if isinstance(route_handler, HTTPRouteHandler)
+                else route.scope_type.value
+            )
+            raise ImproperlyConfiguredException(
+                f"Error building middleware stack for {route_kind} {route.path} "
+                f"(handler {get_name(cast(AnyCallable, route_handler.fn))}): {e!r}"
+            ) from e
 
     def register(self, value: ControllerRouterHandler) -> None:  # type: ignore[override]
         """
@@ -1,11 +1,15 @@
 from inspect import getfullargspec, isawaitable, ismethod
-from typing import TYPE_CHECKING, Any, Dict, List, Set, Tuple, cast
+from typing import TYPE_CHECKING, Any, Dict, List, Optional, Set, Tuple, cast
 
 from starlette.routing import Router as StarletteRouter
 from starlette.types import ASGIApp, Receive, Scope, Send
 
 from starlite.enums import ScopeType
-from starlite.exceptions import MethodNotAllowedException, NotFoundException
+from starlite.exceptions import (
+    ImproperlyConfiguredException,
+    MethodNotAllowedException,
+    NotFoundException,
+)
 from starlite.parsers import parse_path_params
 from starlite.types import LifeCycleHandler
 
@@ -13,6 +17,24 @@ if TYPE_CHECKING:  # pragma: no cover
     from starlite.app import Starlite
 
 
+def _get_required_scope_value(scope: Scope, key: str) -> str:
+    """
+    Retrieves the string value of the given key from the scope.
+
+    Raises ImproperlyConfiguredException if the key is missing or its value is not a string
+    """
+    value = scope.get(key)
+    if isinstance(value, str):
+        return value
+    scope_type = scope.get("type")
+    target = f"{scope_type} request" if isinstance(scope_type, str) else "request of unknown type"
+    if value is None:
+        raise ImproperlyConfiguredException(f"ASGI scope is missing required key '{key}' for {target}")
+    raise ImproperlyConfiguredException(
+        f"ASGI scope key '{key}' must be a string, got {type(value).__name__} for {target}"
+    )
+
+
 class StarliteASGIRouter(StarletteRouter):
     """
     This class extends the Starlette Router class and *is* the ASGI app used in Starlite
@@ -31,12 +53,18 @@ class StarliteASGIRouter(StarletteRouter):
         """
         Traverses the application route mapping and retrieves the correct node for the request url.
 
+        Mounts - static files paths and ASGI routes without child routes - also serve the paths below them,
+        a path that does not lead to a route is served by the deepest mount it passed through.
+
         Raises NotFoundException if no correlating node is found
         """
         path_params: List[str] = []
         cur = self.app.route_map
         components = ["/", *[component for component in path.split("/") if component]]
-        for component in components:
+        mount_match: Optional[Tuple[Dict[str, Any], int, int]] = None
+        for index, component in enumerate(components):
+            if cur.get("_is_mount"):
+                mount_match = (cur, index, len(path_params))
             components_set = cast(Set[str], cur["_components"])
             if component in components_set:
                 cur = cast(Dict[str, Any], cur[component])
@@ -45,20 +73,62 @@ class StarliteASGIRouter(StarletteRouter):
                 path_params.append(component)
                 cur = cast(Dict[str, Any], cur["*"])
                 continue
-            if cur.get("static_path"):
-                static_path = cast(str, cur["static_path"])
-                if static_path != "/":
-                    scope["path"] = scope["path"].replace(static_path, "")
-                continue
+            break
+        else:
+            if cur.get("_is_mount"):
+                mount_match = (cur, len(components), len(path_params))
+            elif "_asgi_handlers" in cur:
+                return cur, path_params
+        if not mount_match:
             raise NotFoundException()
-        return cur, path_params
+        cur, index, path_params_count = mount_match
+        mount_components, path_components = components[1:index], components[index:]
+        asgi_handlers = cast(Dict[str, ASGIApp], cur["_asgi_handlers"])
+        if path_components:
+            # the rest of the path is served by the mounted ASGI app, not by the handlers of the mount path itself
+            cur = {**cur, "_asgi_handlers": {ScopeType.ASGI: asgi_handlers[ScopeType.ASGI]}}
+        elif any(key != ScopeType.ASGI for key in asgi_handlers):
+            # the mount path itself is shared with other handlers, the scope is only set up if the mounted app serves it
+            mounted_app = asgi_handlers[ScopeType.ASGI]
+
+            async def mount_handler(scope: Scope, receive: Receive, send: Send) -> None:
+                self.mount_asgi_app(scope=scope, mount_components=mount_components, path_components=path_components)
+                await mounted_app(scope, receive, send)
+
+            return {**cur, "_asgi_handlers": {**asgi_handlers, ScopeType.ASGI: mount_handler}}, path_params
+        self.mount_asgi_app(scope=scope, mount_components=mount_components, path_components=path_components)
+        return cur, path_params[:path_params_count]
+
+    @staticmethod
+    def mount_asgi_app(scope: Scope, mount_components: List[str], path_components: List[str]) -> None:
+        """
+        Sets up the scope for a mounted ASGI app, following ASGI mount semantics:
+        the mount path is appended to 'root_path' and 'path' is reduced to the part that follows it.
+
+        Note: the mounted app receives a 'path' that excludes 'root_path', which is the convention of Starlette's
+        'Mount' and what its apps (e.g. 'StaticFiles') expect, regardless of 'strip_root_path'.
+        """
+        if mount_components:
+            scope["root_path"] = cast(str, scope.get("root_path", "")).rstrip("/") + "/" + "/".join(mount_components)
+        mounted_path = "/" + "/".join(path_components)
+        if path_components and cast(str, scope["path"]).endswith("/"):
+            mounted_path += "/"
+        scope["path"] = mounted_path
 
     def parse_scope_to_route(self, scope: Scope) -> Tuple[Dict[str, ASGIApp], bool]:
         """
         Given a scope object, retrieve the _asgi_handlers and _is_asgi values from correct trie node.
         """
 
-        path = cast(str, scope["path"]).strip()
+        path = _get_required_scope_value(scope, "path").strip()
+        if "\x00" in path:
+            raise NotFoundException()
+        if self.app.strip_root_path:
+            # per the ASGI spec 'path' includes the 'root_path' the app is mounted under, which routes are not aware of.
+            # Starlette's 'Mount' removes the prefix itself, apps mounted that way should disable 'strip_root_path'
+            root_path = cast(str, scope.get("root_path", "")).rstrip("/")
+            if root_path and (path == root_path or path.startswith(root_path + "/")):
+                path = path[len(root_path) :] or "/"
         if path != "/" and path.endswith("/"):
             path = path.rstrip("/")
         if path in self.app.plain_routes:
@@ -76,15 +146,34 @@ class StarliteASGIRouter(StarletteRouter):
     @staticmethod
     def resolve_asgi_app(scope: Scope, asgi_handlers: Dict[str, ASGIApp], is_asgi: bool) -> ASGIApp:
         """
-        Given a scope, retrieves the correct ASGI App for the route
+        Given a scope, retrieves the correct ASGI App for the route.
+
+        The ASGI handler is only used if the route has no handlers for the scope's protocol, i.e. an unsupported
+        method on a route with http handlers raises MethodNotAllowedException.
+        Raises NotFoundException if the route does not support the scope's type at all.
         """
+        scope_type = _get_required_scope_value(scope, "type")
+        if scope_type == ScopeType.HTTP:
+            # handlers are always registered with uppercase methods, but some proxies forward them lowercased
+            method = _get_required_scope_value(scope, "method").upper()
+            scope["method"] = method
+            if method in asgi_handlers:
+                return asgi_handlers[method]
+            if any(key not in (ScopeType.WEBSOCKET, ScopeType.ASGI) for key in asgi_handlers):
+                raise MethodNotAllowedException()
+            if is_asgi:
+                return asgi_handlers[ScopeType.ASGI]
+            raise NotFoundException(
+                f"Path {scope['path']!r} does not accept http requests, it only supports websocket connections"
+            )
+        if ScopeType.WEBSOCKET in asgi_handlers:
+            return asgi_handlers[ScopeType.WEBSOCKET]
         if is_asgi:
             return asgi_handlers[ScopeType.ASGI]
-        if scope["type"] == ScopeType.HTTP:
-            if scope["method"] not in asgi_handlers:
-                raise MethodNotAllowedException()
-            return asgi_handlers[scope["method"]]
-        return asgi_handlers[ScopeType.WEBSOCKET]
+        raise NotFoundException(
+            f"Path {scope['path']!r} does not accept websocket connections, "
+            f"it only supports the http methods {', '.join(sorted(asgi_handlers))}"
+        )
 
     async def __call__(self, scope: Scope, receive: Receive, send: Send) -> None:
         """
@@ -95,6 +184,11 @@ class StarliteASGIRouter(StarletteRouter):
             asgi_handler = self.resolve_asgi_app(scope=scope, asgi_handlers=asgi_handlers, is_asgi=is_asgi)
         except KeyError as e:
             raise NotFoundException() from e
+        except MethodNotAllowedException as e:
+            if self.app.method_not_allowed_as_404:
+                # avoids disclosing that the path exists
+                raise NotFoundException() from e
+            raise
         await asgi_handler(scope, receive, send)
 
     async def call_lifecycle_handler(self, handler: LifeCycleHandler) -> None:
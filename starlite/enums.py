@@ -1,4 +1,5 @@
 from enum import Enum
+from typing import Optional
 
 
 class HttpMethod(str, Enum):
@@ -7,6 +8,14 @@ class HttpMethod(str, Enum):
     PUT = "PUT"
     PATCH = "PATCH"
     DELETE = "DELETE"
+    HEAD = "HEAD"
+
+    @classmethod
+    def _missing_(cls, value: object) -> Optional["HttpMethod"]:
+        # allows registering handlers with e.g. "get", which is normalized to HttpMethod.GET
+        if isinstance(value, str) and value.upper() in cls.__members__:
+            return cls[value.upper()]
+        return None
 
 
 class MediaType(str, Enum):
@@ -121,9 +121,9 @@ class HTTPRouteHandler(BaseRouteHandler):
         if isinstance(http_method, list):
             self.http_method: Union[List[str], str] = [v.upper() for v in http_method]
             if len(http_method) == 1:
-                self.http_method = http_method[0]
+                self.http_method = self.http_method[0]
         else:
-            self.http_method = http_method.value if isinstance(http_method, HttpMethod) else http_method
+            self.http_method = http_method.value if isinstance(http_method, HttpMethod) else http_method.upper()
         if status_code:
             self.status_code = status_code
         elif isinstance(self.http_method, list):
@@ -73,12 +73,32 @@ class BaseRoute:
 
         param_type_map = {"str": str, "int": int, "float": float, "uuid": UUID}
 
+        if any(ord(char) < 32 or char == "\x7f" for char in path):
+            raise ImproperlyConfiguredException(f"Path {path!r} contains control characters")
+
+        for segment in path.split("/"):
+            if ("{" in segment or "}" in segment) and not (
+                segment.startswith("{") and segment.endswith("}") and segment.count("{") == segment.count("}") == 1
+            ):
+                raise ImproperlyConfiguredException(
+                    f"Invalid path parameter declaration {segment!r} in path {path!r}. "
+                    "Path parameters should take up a whole path segment and be declared using the following pattern: "
+                    "'{parameter_name:type}', e.g. '/my-path/{my_param:int}'"
+                )
+
         for param in param_match_regex.findall(path):
             if ":" not in param:
                 raise ImproperlyConfiguredException(
                     "Path parameters should be declared with a type using the following pattern: '{parameter_name:type}', e.g. '/my-path/{my_param:int}'"
                 )
             param_name, param_type = (p.strip() for p in param.split(":"))
+            if any(path_parameter["name"] == param_name for path_parameter in path_parameters):
+                raise ImproperlyConfiguredException(f"Duplicate parameter '{param_name}' detected in '{path}'.")
+            if param_type not in param_type_map:
+                raise ImproperlyConfiguredException(
+                    f"Path parameter '{param_name}' in '{path}' has an unsupported type '{param_type}', "
+                    f"supported types are: {', '.join(param_type_map)}"
+                )
             path_format = path_format.replace(param, param_name)
             path_parameters.append({"name": param_name, "type": param_type_map[param_type], "full": param})
         return path, path_format, path_parameters
@@ -89,12 +109,7 @@ class BaseRoute:
         """
         dependencies = route_handler.resolve_dependencies()
         signature_model = get_signature_model(route_handler)
-        path_parameters = set()
-        for param in self.path_parameters:
-            param_name = param["name"]
-            if param_name in path_parameters:
-                raise ImproperlyConfiguredException(f"Duplicate parameter '{param_name}' detected in '{self.path}'.")
-            path_parameters.add(param_name)
+        path_parameters = {param["name"] for param in self.path_parameters}
         return KwargsModel.create_for_signature_model(
             signature_model=signature_model, dependencies=dependencies, path_parameters=path_parameters
         )
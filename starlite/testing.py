@@ -124,6 +124,7 @@ def create_test_client(
     exception_handlers: Optional[Dict[Union[int, Type[Exception]], ExceptionHandler]] = None,
     guards: Optional[List[Guard]] = None,
     gzip_config: Optional[GZIPConfig] = None,
+    method_not_allowed_as_404: bool = False,
     middleware: Optional[List[Middleware]] = None,
     on_shutdown: Optional[List[LifeCycleHandler]] = None,
     on_startup: Optional[List[LifeCycleHandler]] = None,
@@ -132,6 +133,7 @@ def create_test_client(
     raise_server_exceptions: bool = True,
     root_path: str = "",
     static_files_config: Optional[Union[StaticFilesConfig, List[StaticFilesConfig]]] = None,
+    strip_root_path: bool = True,
     template_config: Optional[TemplateConfig] = None,
 ) -> TestClient:
     """Create a TestClient"""
@@ -146,6 +148,7 @@ def create_test_client(
             exception_handlers=exception_handlers,
             guards=guards,
             gzip_config=gzip_config,
+            method_not_allowed_as_404=method_not_allowed_as_404,
             middleware=middleware,
             on_shutdown=on_shutdown,
             on_startup=on_startup,
@@ -153,6 +156,7 @@ def create_test_client(
             plugins=plugins,
             route_handlers=cast(Any, route_handlers if isinstance(route_handlers, list) else [route_handlers]),
             static_files_config=static_files_config,
+            strip_root_path=strip_root_path,
             template_config=template_config,
         ),
         backend=backend,
@@ -1,7 +1,10 @@
-from starlette.status import HTTP_200_OK
+from typing import Any, Optional
+
+import pytest
+from starlette.status import HTTP_200_OK, HTTP_404_NOT_FOUND, HTTP_405_METHOD_NOT_ALLOWED
 from starlette.types import Receive, Scope, Send
 
-from starlite import Controller, MediaType, Response, asgi
+from starlite import Controller, MediaType, Request, Response, WebSocket, asgi, get, websocket
 from starlite.testing import create_test_client
 
 
@@ -30,3 +33,176 @@ def test_handle_asgi() -> None:
         response = client.get("/asgi")
         assert response.status_code == HTTP_200_OK
         assert response.text == "Hello World"
+
+
+def test_handle_asgi_alongside_http_and_websocket_handlers() -> None:
+    @get(path=["/all", "/http"], media_type=MediaType.TEXT)
+    def http_handler() -> str:
+        return "http"
+
+    @websocket(path=["/all", "/websocket"])
+    async def websocket_handler(socket: WebSocket) -> None:
+        await socket.accept()
+        await socket.send_text("websocket")
+        await socket.close()
+
+    @asgi(path=["/all", "/http", "/websocket"])
+    async def asgi_handler(scope: Scope, receive: Receive, send: Send) -> None:
+        if scope["type"] == "http":
+            response = Response("asgi", media_type=MediaType.TEXT, status_code=HTTP_200_OK)
+            await response(scope, receive, send)
+        else:
+            socket = WebSocket(scope=scope, receive=receive, send=send)
+            await socket.accept()
+            await socket.send_text("asgi")
+            await socket.close()
+
+    with create_test_client([http_handler, websocket_handler, asgi_handler]) as client:
+        response = client.get("/all")
+        assert response.text == "http"
+        response = client.post("/all")
+        assert response.status_code == HTTP_405_METHOD_NOT_ALLOWED
+        with client.websocket_connect("/all") as ws:
+            assert ws.receive_text() == "websocket"
+
+        response = client.get("/http")
+        assert response.text == "http"
+        response = client.post("/http")
+        assert response.status_code == HTTP_405_METHOD_NOT_ALLOWED
+        with client.websocket_connect("/http") as ws:
+            assert ws.receive_text() == "asgi"
+
+        response = client.get("/websocket")
+        assert response.text == "asgi"
+        with client.websocket_connect("/websocket") as ws:
+            assert ws.receive_text() == "websocket"
+
+
+def test_handle_asgi_mount() -> None:
+    @asgi(path="/admin")
+    async def admin_asgi_handler(scope: Scope, receive: Receive, send: Send) -> None:
+        response = Response(f"{scope['root_path']}|{scope['path']}", media_type=MediaType.TEXT, status_code=HTTP_200_OK)
+        await response(scope, receive, send)
+
+    @get(path="/admin", media_type=MediaType.TEXT)
+    def admin_http_handler() -> str:
+        return "http"
+
+    @asgi(path="/users/{user_id:int}")
+    async def user_asgi_handler(scope: Scope, receive: Receive, send: Send) -> None:
+        response = Response(
+            f"{scope['path_params']['user_id']}|{scope['root_path']}|{scope['path']}",
+            media_type=MediaType.TEXT,
+            status_code=HTTP_200_OK,
+        )
+        await response(scope, receive, send)
+
+    @asgi(path="/docs")
+    async def docs_asgi_handler(scope: Scope, receive: Receive, send: Send) -> None:
+        response = Response("docs", media_type=MediaType.TEXT, status_code=HTTP_200_OK)
+        await response(scope, receive, send)
+
+    @get(path="/docs/{page:int}")
+    def docs_page_handler(page: int) -> None:
+        ...
+
+    with create_test_client(
+        [admin_asgi_handler, admin_http_handler, user_asgi_handler, docs_asgi_handler, docs_page_handler]
+    ) as client:
+        response = client.get("/admin/anything/deeper")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "/admin|/anything/deeper"
+        response = client.post("/admin/anything/deeper/")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "/admin|/anything/deeper/"
+        response = client.get("/admin")
+        assert response.text == "http"
+        response = client.post("/admin")
+        assert response.status_code == HTTP_405_METHOD_NOT_ALLOWED
+
+        response = client.get("/users/1/files/report")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "1|/users/1|/files/report"
+
+        # an ASGI route with child routes is not a mount
+        response = client.get("/docs")
+        assert response.text == "docs"
+        response = client.get("/docs/anything/deeper")
+        assert response.status_code == HTTP_404_NOT_FOUND
+
+    with create_test_client(admin_asgi_handler, root_path="/api") as client:
+        response = client.get("/api/admin/anything")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "/api/admin|/anything"
+
+
+async def mounted_app(scope: Scope, receive: Receive, send: Send) -> None:
+    if scope["type"] == "websocket":
+        socket = WebSocket(scope=scope, receive=receive, send=send)
+        await socket.accept()
+        await socket.send_text(f"{scope['root_path']}|{scope['path']}")
+        await socket.close()
+        return
+    response = Response(f"{scope['root_path']}|{scope['path']}", media_type=MediaType.TEXT, status_code=HTTP_200_OK)
+    await response(scope, receive, send)
+
+
+def test_handle_asgi_mount_path() -> None:
+    with create_test_client(asgi(path="/admin")(mounted_app)) as client:
+        response = client.get("/admin")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "/admin|/"
+        response = client.get("/admin/")
+        assert response.text == "/admin|/"
+
+    with create_test_client(asgi(path="/admin")(mounted_app), root_path="/api") as client:
+        response = client.get("/api/admin")
+        assert response.text == "/api/admin|/"
+
+
+def test_handle_asgi_mount_path_shared_with_other_handlers() -> None:
+    @get(path="/admin", media_type=MediaType.TEXT)
+    def http_handler(request: Request) -> str:
+        return f"{request.scope['root_path']}|{request.scope['path']}"
+
+    with create_test_client([asgi(path="/admin")(mounted_app), http_handler]) as client:
+        response = client.get("/admin")
+        assert response.text == "|/admin"
+        with client.websocket_connect("/admin") as ws:
+            assert ws.receive_text() == "/admin|/"
+
+
+@pytest.mark.parametrize("child_path", ["/admin/health", "/admin/{user_id:int}"])
+def test_handle_asgi_with_child_routes_is_not_a_mount(child_path: str) -> None:
+    @get(path=child_path)
+    def child_handler(**kwargs: Any) -> None:
+        ...
+
+    with create_test_client([asgi(path="/admin")(mounted_app), child_handler]) as client:
+        response = client.get("/admin")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "|/admin"
+        response = client.get("/admin/1" if "{" in child_path else "/admin/health")
+        assert response.status_code == HTTP_200_OK
+        response = client.get("/admin/anything/deeper")
+        assert response.status_code == HTTP_404_NOT_FOUND
+
+
+@pytest.mark.parametrize(
+    "child_path, expected_status_code",
+    [(None, HTTP_200_OK), ("/health", HTTP_404_NOT_FOUND), ("/users/{user_id:int}", HTTP_404_NOT_FOUND)],
+)
+def test_handle_asgi_root_mount(child_path: Optional[str], expected_status_code: int) -> None:
+    @get(path=child_path)
+    def child_handler(**kwargs: Any) -> None:
+        ...
+
+    route_handlers = [asgi(path="/")(mounted_app), *([child_handler] if child_path else [])]
+    with create_test_client(route_handlers) as client:
+        response = client.get("/")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "|/"
+        response = client.get("/anything/deeper")
+        assert response.status_code == expected_status_code
+        if expected_status_code == HTTP_200_OK:
+            assert response.text == "|/anything/deeper"
@@ -22,6 +22,12 @@ def test_route_handler_validation_http_method() -> None:
     for value in [*list(HttpMethod), *list(map(lambda x: x.upper(), list(HttpMethod)))]:
         assert route(http_method=value)  # type: ignore
 
+    # normalizes the casing of http methods
+    for value in ["get", "post", "put", "patch", "delete", "head", "Get", "Post", "Put", "Patch", "Delete", "Head"]:
+        assert route(http_method=value).http_method == value.upper()  # type: ignore
+        assert route(http_method=[value]).http_method == value.upper()  # type: ignore
+    assert route(http_method=["get", "Post"]).http_method == ["GET", "POST"]  # type: ignore
+
     # raises for invalid values
     with pytest.raises(ValidationError):
         HTTPRouteHandler(http_method="deleze")  # type: ignore
@@ -15,6 +15,7 @@ from typing_extensions import Type
 from starlite import (
     Controller,
     CORSConfig,
+    ImproperlyConfiguredException,
     MiddlewareProtocol,
     Request,
     Response,
@@ -193,3 +194,28 @@ def test_middleware_call_order() -> None:
         client.get("/router/controller/handler")
 
         assert results == [0, 1, 2, 3, 4, 5, 6, 7]
+
+
+def test_middleware_stack_build_error_names_route() -> None:
+    class BrokenMiddleware(MiddlewareProtocol):
+        def __init__(self, app: ASGIApp) -> None:
+            raise RuntimeError("broken")
+
+        async def __call__(self, scope: Scope, receive: Receive, send: Send) -> None:  # pragma: no cover
+            ...
+
+    @get("/working")
+    def working_handler() -> None:
+        ...
+
+    @post("/users/{user_id:int}", middleware=[BrokenMiddleware])
+    def broken_handler() -> None:
+        ...
+
+    with pytest.raises(ImproperlyConfiguredException) as exc_info:
+        Starlite(route_handlers=[working_handler, broken_handler])
+    assert exc_info.value.detail == (
+        "Error building middleware stack for POST /users/{user_id:int} (handler broken_handler): "
+        "RuntimeError('broken')"
+    )
+    assert isinstance(exc_info.value.__cause__, RuntimeError)
@@ -1,6 +1,9 @@
+from typing import Any
+
 import pytest
+from starlette.types import Receive, Scope, Send
 
-from starlite import ImproperlyConfiguredException, Starlite, get
+from starlite import Controller, ImproperlyConfiguredException, Starlite, asgi, get
 
 
 def test_register_validation_duplicate_handlers_for_same_route_and_method() -> None:
@@ -14,3 +17,71 @@ def test_register_validation_duplicate_handlers_for_same_route_and_method() -> N
 
     with pytest.raises(ImproperlyConfiguredException):
         Starlite(route_handlers=[first_route_handler, second_route_handler])
+
+
+@pytest.mark.parametrize(
+    "path",
+    [
+        "/users/{user_id:int/posts",
+        "/users/user_id:int}/posts",
+        "/users/{{user_id:int}}",
+        "/reports/report-{year:int}.pdf",
+        "/range/{start:int}-{end:int}",
+    ],
+)
+def test_register_validation_invalid_path_parameter_syntax(path: str) -> None:
+    @get(path=path)
+    def handler() -> None:
+        pass
+
+    with pytest.raises(ImproperlyConfiguredException):
+        Starlite(route_handlers=[handler])
+
+
+@asgi(path="/map/{id:int}/compare/{id:str}")
+async def duplicate_path_parameters_asgi_handler(scope: Scope, receive: Receive, send: Send) -> None:
+    pass
+
+
+class DuplicatePathParametersController(Controller):
+    path = "/map/{id:int}"
+
+    @get(path="/compare/{id:int}")
+    def compare(self) -> None:
+        pass
+
+
+@pytest.mark.parametrize(
+    "route_handler, path",
+    [
+        (duplicate_path_parameters_asgi_handler, "/map/{id:int}/compare/{id:str}"),
+        (DuplicatePathParametersController, "/map/{id:int}/compare/{id:int}"),
+    ],
+)
+def test_register_validation_duplicate_path_parameters(route_handler: Any, path: str) -> None:
+    with pytest.raises(ImproperlyConfiguredException) as exc_info:
+        Starlite(route_handlers=[route_handler])
+    assert exc_info.value.detail == f"Duplicate parameter 'id' detected in '{path}'."
+
+
+def test_register_validation_unsupported_path_parameter_type() -> None:
+    @get(path="/users/{user_id:integer}")
+    def handler() -> None:
+        pass
+
+    with pytest.raises(ImproperlyConfiguredException) as exc_info:
+        Starlite(route_handlers=[handler])
+    assert exc_info.value.detail == (
+        "Path parameter 'user_id' in '/users/{user_id:integer}' has an unsupported type 'integer', "
+        "supported types are: str, int, float, uuid"
+    )
+
+
+@pytest.mark.parametrize("path", ["/users/\x00", "/users\r\n/posts", "/\x7f"])
+def test_register_validation_control_characters_in_path(path: str) -> None:
+    @get(path=path)
+    def handler() -> None:
+        pass
+
+    with pytest.raises(ImproperlyConfiguredException):
+        Starlite(route_handlers=[handler])
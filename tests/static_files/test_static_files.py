@@ -3,6 +3,7 @@ from typing import Any
 import pytest
 from pydantic import ValidationError
 
+from starlite import ImproperlyConfiguredException, MediaType, Starlite, get
 from starlite.config import StaticFilesConfig
 from starlite.testing import create_test_client
 
@@ -28,6 +29,153 @@ def test_staticfiles_for_slash_path(tmpdir: Any) -> None:
         assert response.text == "content"
 
 
+def test_staticfiles_for_slash_path_nested_file(tmpdir: Any) -> None:
+    favicon = tmpdir.join("favicon.ico")
+    favicon.write("icon")
+    nested = tmpdir.mkdir("css").join("style.css")
+    nested.write("content")
+
+    static_files_config = StaticFilesConfig(path="/", directories=[tmpdir])
+    with create_test_client([], static_files_config=static_files_config) as client:
+        response = client.get("/favicon.ico")
+        assert response.status_code == 200
+        assert response.text == "icon"
+        response = client.get("/css/style.css")
+        assert response.status_code == 200
+        assert response.text == "content"
+
+
+def test_staticfiles_strips_mount_path_once(tmpdir: Any) -> None:
+    tmpdir.join("test.txt").write("outer")
+    tmpdir.mkdir("static").join("test.txt").write("inner")
+
+    static_files_config = StaticFilesConfig(path="/static", directories=[tmpdir])
+    with create_test_client([], static_files_config=static_files_config) as client:
+        response = client.get("/static/static/test.txt")
+        assert response.status_code == 200
+        assert response.text == "inner"
+
+
+def test_staticfiles_two_nested_mounts(tmpdir: Any) -> None:
+    outer = tmpdir.mkdir("outer")
+    outer.join("test.txt").write("outer")
+    outer.mkdir("vendor").join("other.txt").write("outer vendor")
+    vendor = tmpdir.mkdir("vendor")
+    vendor.join("test.txt").write("vendor")
+
+    static_files_config = [
+        StaticFilesConfig(path="/static", directories=[outer]),
+        StaticFilesConfig(path="/static/vendor", directories=[vendor]),
+    ]
+    with create_test_client([], static_files_config=static_files_config) as client:
+        response = client.get("/static/test.txt")
+        assert response.status_code == 200
+        assert response.text == "outer"
+        response = client.get("/static/vendor/test.txt")
+        assert response.status_code == 200
+        assert response.text == "vendor"
+        response = client.get("/static/vendor/other.txt")
+        assert response.status_code == 404
+
+
+def test_staticfiles_three_nested_mounts(tmpdir: Any) -> None:
+    outer = tmpdir.mkdir("outer")
+    outer.join("test.txt").write("outer")
+    outer.mkdir("vendor").join("test.txt").write("outer vendor")
+    vendor = tmpdir.mkdir("vendor")
+    vendor.join("test.txt").write("vendor")
+    vendor.mkdir("lib").join("other.txt").write("vendor lib")
+    lib = tmpdir.mkdir("lib")
+    lib.join("test.txt").write("lib")
+
+    static_files_config = [
+        StaticFilesConfig(path="/static", directories=[outer]),
+        StaticFilesConfig(path="/static/vendor/lib", directories=[lib]),
+    ]
+    with create_test_client([], static_files_config=static_files_config) as client:
+        response = client.get("/static/vendor/lib/test.txt")
+        assert response.status_code == 200
+        assert response.text == "lib"
+        # '/static/vendor' is only an intermediate node, so the '/static' mount serves it
+        response = client.get("/static/vendor/test.txt")
+        assert response.status_code == 200
+        assert response.text == "outer vendor"
+
+    static_files_config.insert(1, StaticFilesConfig(path="/static/vendor", directories=[vendor]))
+    with create_test_client([], static_files_config=static_files_config) as client:
+        response = client.get("/static/test.txt")
+        assert response.text == "outer"
+        response = client.get("/static/vendor/test.txt")
+        assert response.text == "vendor"
+        response = client.get("/static/vendor/lib/test.txt")
+        assert response.text == "lib"
+        response = client.get("/static/vendor/lib/other.txt")
+        assert response.status_code == 404
+
+
+@pytest.mark.parametrize("request_path", ["/static/assets/css/style.css", "/api/static/assets/css/style.css"])
+def test_staticfiles_mount_semantics(tmpdir: Any, request_path: str) -> None:
+    static_files_config = StaticFilesConfig(path="/static/assets", directories=[tmpdir])
+    app = Starlite(route_handlers=[], static_files_config=static_files_config)
+    scope = {"type": "http", "method": "GET", "path": request_path, "root_path": "/api"}
+    app.asgi_router.parse_scope_to_route(scope=scope)
+    assert scope["root_path"] == "/api/static/assets"
+    assert scope["path"] == "/css/style.css"
+
+
+def test_staticfiles_mount_path_html_mode(tmpdir: Any) -> None:
+    path = tmpdir.join("index.html")
+    path.write("index")
+
+    static_files_config = StaticFilesConfig(path="/static", directories=[tmpdir], html_mode=True)
+    with create_test_client([], static_files_config=static_files_config) as client:
+        response = client.get("/static")
+        assert response.status_code == 200
+        assert response.text == "index"
+
+
+def test_staticfiles_path_cannot_be_shared_with_route_handlers(tmpdir: Any) -> None:
+    @get("/static")
+    def handler_fn() -> None:
+        ...
+
+    static_files_config = StaticFilesConfig(path="/static", directories=[tmpdir])
+    with pytest.raises(ImproperlyConfiguredException):
+        Starlite(route_handlers=[handler_fn], static_files_config=static_files_config)
+
+
+@pytest.mark.parametrize("file_path", ["a.txt", "b/a.txt", "b/c/d/e/a.txt"])
+def test_staticfiles_extra_segments(tmpdir: Any, file_path: str) -> None:
+    *directories, file_name = file_path.split("/")
+    directory = tmpdir
+    for name in directories:
+        directory = directory.mkdir(name)
+    directory.join(file_name).write(file_path)
+
+    static_files_config = StaticFilesConfig(path="/static", directories=[tmpdir])
+    with create_test_client([], static_files_config=static_files_config) as client:
+        response = client.get(f"/static/{file_path}")
+        assert response.status_code == 200
+        assert response.text == file_path
+
+
+def test_staticfiles_deeper_route_wins(tmpdir: Any) -> None:
+    tmpdir.mkdir("docs").mkdir("1").join("test.txt").write("content")
+
+    @get("/static/docs/{page:int}", media_type=MediaType.TEXT)
+    def handler_fn(page: int) -> str:
+        return f"page {page}"
+
+    static_files_config = StaticFilesConfig(path="/static", directories=[tmpdir])
+    with create_test_client(handler_fn, static_files_config=static_files_config) as client:
+        response = client.get("/static/docs/1")
+        assert response.status_code == 200
+        assert response.text == "page 1"
+        response = client.get("/static/docs/1/test.txt")
+        assert response.status_code == 200
+        assert response.text == "content"
+
+
 def test_config_validation(tmpdir: Any) -> None:
     path = tmpdir.join("text.txt")
     path.write("content")
@@ -1,4 +1,4 @@
-from typing import Any, Callable, Optional
+from typing import Any, Callable, Dict, Optional
 from uuid import uuid4
 
 import pytest
@@ -16,9 +16,14 @@ from starlite import (
     HTTPRouteHandler,
     ImproperlyConfiguredException,
     MediaType,
+    NotFoundException,
+    Starlite,
+    WebSocket,
     delete,
     get,
     post,
+    route,
+    websocket,
 )
 from starlite.testing import create_test_client
 from tests import Person, PersonFactory
@@ -166,6 +171,34 @@ async def test_http_route_raises_for_unsupported_method() -> None:
         assert response.status_code == HTTP_405_METHOD_NOT_ALLOWED
 
 
+@pytest.mark.parametrize(
+    "method_not_allowed_as_404, expected_status_code",
+    [(False, HTTP_405_METHOD_NOT_ALLOWED), (True, HTTP_404_NOT_FOUND)],
+)
+def test_method_not_allowed_as_404(method_not_allowed_as_404: bool, expected_status_code: int) -> None:
+    @get("/admin")
+    def http_handler() -> None:
+        ...
+
+    @websocket("/admin")
+    async def websocket_handler(socket: WebSocket) -> None:
+        await socket.accept()
+        await socket.send_text("websocket")
+        await socket.close()
+
+    with create_test_client(
+        [http_handler, websocket_handler], method_not_allowed_as_404=method_not_allowed_as_404
+    ) as client:
+        response = client.get("/admin")
+        assert response.status_code == HTTP_200_OK
+        response = client.put("/admin")
+        assert response.status_code == expected_status_code
+        response = client.get("/missing")
+        assert response.status_code == HTTP_404_NOT_FOUND
+        with client.websocket_connect("/admin") as ws:
+            assert ws.receive_text() == "websocket"
+
+
 def test_path_order() -> None:
     @get(path=["/something/{some_id:int}", "/"], media_type=MediaType.TEXT)
     def handler_fn(some_id: int = 1) -> str:
@@ -187,3 +220,164 @@ def test_conflicting_paths() -> None:
 
     with pytest.raises(ImproperlyConfiguredException):
         create_test_client(handler_fn)
+
+
+def test_http_and_websocket_handlers_on_the_same_path_with_parameters() -> None:
+    @get(path="/users/{user_id:int}", media_type=MediaType.TEXT)
+    def http_handler(user_id: int) -> str:
+        return str(user_id)
+
+    @websocket(path="/users/{user_id:int}")
+    async def websocket_handler(socket: WebSocket, user_id: int) -> None:
+        await socket.accept()
+        await socket.send_text(str(user_id))
+        await socket.close()
+
+    with create_test_client([http_handler, websocket_handler]) as client:
+        response = client.get("/users/1")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "1"
+        with client.websocket_connect("/users/2") as ws:
+            assert ws.receive_text() == "2"
+
+
+@pytest.mark.parametrize(
+    "root_path, request_path, expected_status_code",
+    [
+        ("", "/sub/path", HTTP_200_OK),
+        ("/api", "/api/sub/path", HTTP_200_OK),
+        ("/api/", "/api/sub/path", HTTP_200_OK),
+        ("/api", "/sub/path", HTTP_200_OK),
+        ("/ap", "/api/sub/path", HTTP_404_NOT_FOUND),
+    ],
+)
+def test_root_path_handling(root_path: str, request_path: str, expected_status_code: int) -> None:
+    @get("/sub/path")
+    def handler_fn() -> None:
+        ...
+
+    with create_test_client(handler_fn, root_path=root_path) as client:
+        response = client.get(request_path)
+        assert response.status_code == expected_status_code
+
+
+def test_root_path_equal_to_path_resolves_to_root() -> None:
+    @get("/", media_type=MediaType.TEXT)
+    def handler_fn() -> str:
+        return "root"
+
+    with create_test_client(handler_fn, root_path="/api") as client:
+        response = client.get("/api")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "root"
+
+
+@pytest.mark.parametrize("strip_root_path, expected_status_code", [(True, HTTP_404_NOT_FOUND), (False, HTTP_200_OK)])
+def test_strip_root_path_flag(strip_root_path: bool, expected_status_code: int) -> None:
+    @get("/api/health")
+    def handler_fn() -> None:
+        ...
+
+    with create_test_client(handler_fn, root_path="/api", strip_root_path=strip_root_path) as client:
+        response = client.get("/api/health")
+        assert response.status_code == expected_status_code
+
+
+@pytest.mark.parametrize(
+    "scope, message",
+    [
+        ({"type": "http", "method": "GET"}, "ASGI scope is missing required key 'path' for http request"),
+        (
+            {"type": "http", "path": b"/", "method": "GET"},
+            "ASGI scope key 'path' must be a string, got bytes for http request",
+        ),
+        ({"path": "/", "method": "GET"}, "ASGI scope is missing required key 'type' for request of unknown type"),
+        ({"type": "http", "path": "/"}, "ASGI scope is missing required key 'method' for http request"),
+    ],
+)
+def test_router_raises_for_invalid_scope(scope: Dict[str, Any], message: str) -> None:
+    @get("/")
+    def handler_fn() -> None:
+        ...
+
+    router = Starlite(route_handlers=[handler_fn]).asgi_router
+    with pytest.raises(ImproperlyConfiguredException) as exc_info:
+        asgi_handlers, is_asgi = router.parse_scope_to_route(scope=scope)
+        router.resolve_asgi_app(scope=scope, asgi_handlers=asgi_handlers, is_asgi=is_asgi)
+    assert exc_info.value.detail == message
+
+
+@pytest.mark.parametrize("method", ["GET", "get", "Get"])
+def test_router_normalizes_http_method_case(method: str) -> None:
+    @get("/")
+    def handler_fn() -> None:
+        ...
+
+    router = Starlite(route_handlers=[handler_fn]).asgi_router
+    scope = {"type": "http", "path": "/", "method": method}
+    asgi_handlers, is_asgi = router.parse_scope_to_route(scope=scope)
+    asgi_handler = router.resolve_asgi_app(scope=scope, asgi_handlers=asgi_handlers, is_asgi=is_asgi)
+    assert asgi_handler is asgi_handlers["GET"]
+    assert scope["method"] == "GET"
+
+
+@pytest.mark.parametrize("http_method", ["get", ["get"], ["Get", "post"]])
+def test_lowercase_registered_http_method_is_reachable(http_method: Any) -> None:
+    @route("/", http_method=http_method, status_code=HTTP_200_OK)
+    def handler_fn() -> None:
+        ...
+
+    router = Starlite(route_handlers=[handler_fn]).asgi_router
+    for method in ["GET", "get", "gEt"]:
+        scope = {"type": "http", "path": "/", "method": method}
+        asgi_handlers, is_asgi = router.parse_scope_to_route(scope=scope)
+        assert router.resolve_asgi_app(scope=scope, asgi_handlers=asgi_handlers, is_asgi=is_asgi)
+
+    with create_test_client(handler_fn) as client:
+        response = client.get("/")
+        assert response.status_code == HTTP_200_OK
+
+
+def test_router_raises_not_found_for_unsupported_scope_type() -> None:
+    @get("/http")
+    def http_handler() -> None:
+        ...
+
+    @websocket("/websocket")
+    async def websocket_handler(socket: WebSocket) -> None:
+        ...
+
+    router = Starlite(route_handlers=[http_handler, websocket_handler]).asgi_router
+
+    scope = {"type": "websocket", "path": "/http"}
+    asgi_handlers, is_asgi = router.parse_scope_to_route(scope=scope)
+    with pytest.raises(NotFoundException) as exc_info:
+        router.resolve_asgi_app(scope=scope, asgi_handlers=asgi_handlers, is_asgi=is_asgi)
+    assert (
+        exc_info.value.detail
+        == "Path '/http' does not accept websocket connections, it only supports the http methods GET"
+    )
+
+    scope = {"type": "http", "path": "/websocket", "method": "GET"}
+    asgi_handlers, is_asgi = router.parse_scope_to_route(scope=scope)
+    with pytest.raises(NotFoundException) as exc_info:
+        router.resolve_asgi_app(scope=scope, asgi_handlers=asgi_handlers, is_asgi=is_asgi)
+    assert (
+        exc_info.value.detail
+        == "Path '/websocket' does not accept http requests, it only supports websocket connections"
+    )
+
+    with create_test_client([http_handler, websocket_handler]) as client:
+        response = client.get("/websocket")
+        assert response.status_code == HTTP_404_NOT_FOUND
+
+
+@pytest.mark.parametrize("path", ["/users/\x00", "/users/\x00/", "/\x00"])
+def test_router_rejects_nul_bytes_in_path(path: str) -> None:
+    @get(["/", "/users/{user_id:str}"])
+    def handler_fn() -> None:
+        ...
+
+    router = Starlite(route_handlers=[handler_fn]).asgi_router
+    with pytest.raises(NotFoundException):
+        router.parse_scope_to_route(scope={"type": "http", "path": path, "method": "GET"})